@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::Path;
+
+#[path = "src/ipc_contract.rs"]
+mod ipc_contract;
+
+use ipc_contract::{OpenUrlArgs, PlatformInfo};
+use ts_rs::TS;
+
+/// Describes a single command's invoke name and TypeScript argument/return
+/// types, so `write_command_manifest` can emit a `commands.ts` manifest
+/// alongside the per-type bindings. Lives in `build.rs` rather than
+/// `src/ipc_contract.rs` because it has no use inside `flowent_lib` itself
+/// and would only be dead code there.
+struct CommandSignature {
+    name: &'static str,
+    args_ts: &'static str,
+    returns_ts: &'static str,
+}
+
+/// The full set of commands exposed across the `flowent` plugin, kept here
+/// (rather than derived via reflection) so it stays a single, reviewable
+/// source of truth for the generated bindings.
+const COMMANDS: &[CommandSignature] = &[
+    CommandSignature {
+        name: "plugin:flowent|get_app_version",
+        args_ts: "{}",
+        returns_ts: "string",
+    },
+    CommandSignature {
+        name: "plugin:flowent|get_platform_info",
+        args_ts: "{}",
+        returns_ts: "PlatformInfo",
+    },
+    CommandSignature {
+        name: "plugin:flowent|open_external_url",
+        args_ts: "{ args: OpenUrlArgs }",
+        returns_ts: "void",
+    },
+];
+
+/// Calls `TS::export()` on every IPC contract type so their `.ts` files land
+/// in `src/bindings/` on a plain `cargo build`, without depending on the
+/// crate's tests having run first.
+fn write_type_bindings() {
+    PlatformInfo::export().expect("failed to export PlatformInfo bindings");
+    OpenUrlArgs::export().expect("failed to export OpenUrlArgs bindings");
+}
+
+/// Writes `src/bindings/commands.ts`, a generated manifest of every Tauri
+/// command with its TypeScript argument/return types, importing the
+/// per-struct bindings written by `write_type_bindings`.
+fn write_command_manifest() {
+    let out_dir = Path::new("../src/bindings");
+    fs::create_dir_all(out_dir).expect("failed to create src/bindings");
+
+    let mut contents = String::from(
+        "// AUTO-GENERATED by build.rs. Do not edit by hand.\n\
+         import type { PlatformInfo } from \"./PlatformInfo\";\n\
+         import type { OpenUrlArgs } from \"./OpenUrlArgs\";\n\n\
+         export interface Commands {\n",
+    );
+
+    for command in COMMANDS {
+        contents.push_str(&format!(
+            "  \"{}\": (args: {}) => Promise<{}>;\n",
+            command.name, command.args_ts, command.returns_ts
+        ));
+    }
+
+    contents.push_str("}\n");
+
+    fs::write(out_dir.join("commands.ts"), contents).expect("failed to write commands.ts");
+}
+
+fn main() {
+    tauri_build::build();
+    write_type_bindings();
+    write_command_manifest();
+}
@@ -0,0 +1,110 @@
+//! The `flowent` plugin.
+//!
+//! Bundles the app's general-purpose commands (version/platform info, opening
+//! external URLs) behind their own `plugin:flowent|*` invoke namespace instead
+//! of registering them inline on the main builder. Keeping them here makes the
+//! command surface testable in isolation via a mock app handle.
+
+use tauri::{
+    plugin::{Builder, TauriPlugin},
+    Runtime,
+};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::ipc_contract::{OpenUrlArgs, PlatformInfo};
+
+/// Custom Tauri command to get app version.
+#[tauri::command]
+fn get_app_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+/// Custom Tauri command to get platform info. `std::env::consts::OS` already
+/// resolves to `"android"`/`"ios"` when cross-compiled for those targets, so
+/// no separate mobile branch is needed here.
+#[tauri::command]
+fn get_platform_info() -> PlatformInfo {
+    PlatformInfo {
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        family: std::env::consts::FAMILY.to_string(),
+    }
+}
+
+/// Custom Tauri command to open external URL in default browser. Uses the
+/// already-registered `tauri-plugin-opener` rather than the `open` crate
+/// directly, since `open::that` shells out to a desktop binary that doesn't
+/// exist on mobile. Returns an error for a string that does not parse as a URL.
+#[tauri::command]
+async fn open_external_url<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    args: OpenUrlArgs,
+) -> Result<(), String> {
+    let url = tauri::Url::parse(&args.url)
+        .map_err(|e| format!("Invalid URL '{}': {e}", args.url))?;
+    app.opener()
+        .open_url(url.as_str(), None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+/// Initializes the `flowent` plugin, registering its commands under the
+/// `plugin:flowent|*` invoke namespace.
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::new("flowent")
+        .invoke_handler(tauri::generate_handler![
+            get_app_version,
+            get_platform_info,
+            open_external_url,
+        ])
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tauri::plugin::Plugin;
+
+    #[test]
+    fn get_app_version_returns_crate_version() {
+        assert_eq!(get_app_version(), env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn get_platform_info_reports_current_target() {
+        let info = get_platform_info();
+        assert_eq!(info.os, std::env::consts::OS);
+        assert_eq!(info.arch, std::env::consts::ARCH);
+        assert_eq!(info.family, std::env::consts::FAMILY);
+    }
+
+    /// Builds a mock app with `tauri-plugin-opener` actually registered, so
+    /// commands that depend on it exercise real behavior instead of failing
+    /// on a missing plugin state.
+    fn mock_app_with_opener() -> tauri::App<tauri::test::MockRuntime> {
+        tauri::test::mock_builder()
+            .plugin(tauri_plugin_opener::init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .expect("failed to build mock app")
+    }
+
+    #[tokio::test]
+    async fn open_external_url_rejects_invalid_url() {
+        let app = mock_app_with_opener();
+        let result = open_external_url(
+            app.handle().clone(),
+            OpenUrlArgs {
+                url: "not a url".to_string(),
+            },
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn init_builds_a_plugin_named_flowent() {
+        let app = mock_app_with_opener();
+        let plugin = init::<tauri::test::MockRuntime>();
+        assert_eq!(plugin.name(), "flowent");
+        drop(app);
+    }
+}
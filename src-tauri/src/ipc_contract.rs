@@ -0,0 +1,26 @@
+//! Typed IPC contract shared between the Rust commands and the frontend.
+//!
+//! Every type in here derives `ts_rs::TS`; `build.rs` calls `TS::export()` on
+//! each one directly (rather than relying on the `#[ts(export)]` test shim),
+//! so a plain `cargo build` produces a complete, self-consistent `src/bindings/`
+//! directory for the wasm/JS `invoke` call sites to import, instead of drifting
+//! through ad-hoc `serde_json::Value` payloads.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Response shape of `get_platform_info`.
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../src/bindings/")]
+pub struct PlatformInfo {
+    pub os: String,
+    pub arch: String,
+    pub family: String,
+}
+
+/// Argument shape of `open_external_url`.
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../src/bindings/")]
+pub struct OpenUrlArgs {
+    pub url: String,
+}
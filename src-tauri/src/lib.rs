@@ -3,28 +3,301 @@
 //! This is the main Tauri library for the Flowent desktop app.
 //! It sets up the Tauri runtime with all necessary plugins and commands.
 
-use tauri::Manager;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
-/// Custom Tauri command to get app version
+use tauri::{Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
+#[cfg(desktop)]
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+use tauri_plugin_store::StoreExt;
+#[cfg(desktop)]
+use tauri_plugin_updater::UpdaterExt;
+
+mod flowent_plugin;
+mod ipc_contract;
+
+/// Payload forwarded to the frontend when a second instance of the app is launched,
+/// so the UI can act on the arguments (open a file, start a new note, etc.).
+/// Desktop-only: mobile platforms never launch a second instance of the app.
+#[cfg(desktop)]
+#[derive(Clone, serde::Serialize)]
+struct SecondInstancePayload {
+    args: Vec<String>,
+    cwd: String,
+}
+
+/// Label of the always-on-top quick-capture window.
+#[cfg(desktop)]
+const QUICK_CAPTURE_LABEL: &str = "quick-capture";
+
+/// Default accelerator for summoning the quick-capture overlay.
+#[cfg(desktop)]
+const DEFAULT_QUICK_CAPTURE_SHORTCUT: &str = "Ctrl+Shift+Space";
+
+/// Default accelerator for dismissing the quick-capture overlay. Bound
+/// globally (rather than left to the webview's local keydown handling) so
+/// `Esc` still dismisses the overlay even when it doesn't have focus.
+#[cfg(desktop)]
+const DEFAULT_QUICK_CAPTURE_DISMISS_SHORTCUT: &str = "Escape";
+
+/// Tracks the accelerators currently registered with the OS, keyed by the action
+/// name they trigger, so the settings page can list and rebind them.
+/// Desktop-only: `tauri-plugin-global-shortcut` has no mobile backend.
+#[cfg(desktop)]
+#[derive(Default)]
+struct ShortcutRegistry(Mutex<HashMap<String, String>>);
+
+/// Name of the `tauri-plugin-store` file the chosen accelerators are persisted
+/// to, so rebindings survive an app restart.
+#[cfg(desktop)]
+const SHORTCUTS_STORE_FILE: &str = "shortcuts.json";
+
+/// Key under which the action -> accelerator map is stored.
+#[cfg(desktop)]
+const SHORTCUTS_STORE_KEY: &str = "shortcuts";
+
+/// Writes the current registry out to the shortcuts store.
+#[cfg(desktop)]
+fn persist_shortcuts(app: &tauri::AppHandle, registry: &HashMap<String, String>) {
+    let store = match app.store(SHORTCUTS_STORE_FILE) {
+        Ok(store) => store,
+        Err(err) => {
+            log::error!("Failed to open shortcuts store: {err}");
+            return;
+        }
+    };
+
+    store.set(SHORTCUTS_STORE_KEY, serde_json::json!(registry));
+
+    if let Err(err) = store.save() {
+        log::error!("Failed to persist shortcuts: {err}");
+    }
+}
+
+/// Reads the previously persisted action -> accelerator map, if any.
+#[cfg(desktop)]
+fn load_persisted_shortcuts(app: &tauri::AppHandle) -> HashMap<String, String> {
+    app.store(SHORTCUTS_STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(SHORTCUTS_STORE_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// Shows the quick-capture window if it exists, creating it on first use.
+#[cfg(desktop)]
+fn show_quick_capture(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window(QUICK_CAPTURE_LABEL) {
+        let _ = window.show();
+        let _ = window.set_focus();
+        return;
+    }
+
+    let window = WebviewWindowBuilder::new(
+        app,
+        QUICK_CAPTURE_LABEL,
+        WebviewUrl::App("quick-capture.html".into()),
+    )
+    .title("Quick Capture")
+    .inner_size(480.0, 120.0)
+    .center()
+    .decorations(false)
+    .always_on_top(true)
+    .skip_taskbar(true)
+    .visible(true)
+    .build();
+
+    match window {
+        Ok(window) => {
+            let _ = window.set_focus();
+        }
+        Err(err) => log::error!("Failed to create quick-capture window: {err}"),
+    }
+}
+
+/// Hides the quick-capture window instead of destroying it, so it can be
+/// shown again instantly on the next hotkey press.
+#[cfg(desktop)]
+fn hide_quick_capture(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window(QUICK_CAPTURE_LABEL) {
+        let _ = window.hide();
+    }
+}
+
+/// Registers a global shortcut bound to `action`, tracking it in the registry so it
+/// can later be listed or unregistered. Returns a descriptive error if the OS
+/// refuses the binding (e.g. it's already taken by another application).
+#[cfg(desktop)]
+#[tauri::command]
+fn register_shortcut(app: tauri::AppHandle, accelerator: String, action: String) -> Result<(), String> {
+    let registry = app.state::<ShortcutRegistry>();
+
+    // Rebinding an action must release its previous accelerator first, or the
+    // old binding keeps firing (and becomes unreachable, since the registry
+    // only tracks one accelerator per action).
+    let previous = registry.0.lock().unwrap().get(&action).cloned();
+    if let Some(previous) = previous.filter(|previous| previous != &accelerator) {
+        app.global_shortcut()
+            .unregister(previous.as_str())
+            .map_err(|e| format!("Could not unregister previous shortcut '{previous}': {e}"))?;
+    }
+
+    app.global_shortcut()
+        .on_shortcut(accelerator.as_str(), move |app, _shortcut, event| {
+            if event.state() != ShortcutState::Pressed {
+                return;
+            }
+
+            match action.as_str() {
+                "quick-capture:toggle" => {
+                    if app
+                        .get_webview_window(QUICK_CAPTURE_LABEL)
+                        .map(|w| w.is_visible().unwrap_or(false))
+                        .unwrap_or(false)
+                    {
+                        hide_quick_capture(app);
+                    } else {
+                        show_quick_capture(app);
+                    }
+                }
+                "quick-capture:dismiss" => hide_quick_capture(app),
+                other => log::warn!("No handler registered for shortcut action '{other}'"),
+            }
+        })
+        .map_err(|e| format!("Could not register shortcut '{accelerator}': {e}"))?;
+
+    let snapshot = {
+        let mut shortcuts = registry.0.lock().unwrap();
+        shortcuts.insert(action, accelerator);
+        shortcuts.clone()
+    };
+    persist_shortcuts(&app, &snapshot);
+
+    Ok(())
+}
+
+/// Unregisters the accelerator bound to `action`, if any.
+#[cfg(desktop)]
+#[tauri::command]
+fn unregister_shortcut(app: tauri::AppHandle, action: String) -> Result<(), String> {
+    let registry = app.state::<ShortcutRegistry>();
+    let (accelerator, snapshot) = {
+        let mut shortcuts = registry.0.lock().unwrap();
+        let accelerator = shortcuts.remove(&action);
+        (accelerator, shortcuts.clone())
+    };
+
+    if let Some(accelerator) = accelerator {
+        app.global_shortcut()
+            .unregister(accelerator.as_str())
+            .map_err(|e| format!("Could not unregister shortcut '{accelerator}': {e}"))?;
+        persist_shortcuts(&app, &snapshot);
+    }
+
+    Ok(())
+}
+
+/// Returns the action -> accelerator map currently registered, for the settings page.
+#[cfg(desktop)]
+#[tauri::command]
+fn get_shortcuts(app: tauri::AppHandle) -> HashMap<String, String> {
+    app.state::<ShortcutRegistry>().0.lock().unwrap().clone()
+}
+
+/// Metadata about an available update, returned to the frontend so it can
+/// decide whether to prompt the user before downloading.
+/// Desktop-only: `tauri-plugin-updater` does not support mobile app stores.
+#[cfg(desktop)]
+#[derive(Clone, serde::Serialize)]
+struct UpdateInfo {
+    version: String,
+    current_version: String,
+    date: Option<String>,
+    body: Option<String>,
+}
+
+/// Progress payload emitted while an update is being downloaded: the total
+/// bytes downloaded so far and the content length, if known.
+#[cfg(desktop)]
+#[derive(Clone, serde::Serialize)]
+struct UpdateProgress {
+    downloaded: usize,
+    content_length: Option<u64>,
+}
+
+/// Checks the configured update endpoint for a newer release. The release's
+/// signature is not checked here; that happens in `download_and_install_update`.
+/// Returns `None` when the app is already up to date.
+#[cfg(desktop)]
 #[tauri::command]
-fn get_app_version() -> String {
-    env!("CARGO_PKG_VERSION").to_string()
+async fn check_for_update(app: tauri::AppHandle) -> Result<Option<UpdateInfo>, String> {
+    let update = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {e}"))?;
+
+    Ok(update.map(|update| UpdateInfo {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        date: update.date.map(|d| d.to_string()),
+        body: update.body.clone(),
+    }))
 }
 
-/// Custom Tauri command to get platform info
+/// Downloads and installs the available update, streaming progress to the
+/// frontend via `update://download-progress` and `update://finished` events,
+/// then restarts the app so the install takes effect. The update's signature
+/// is verified against the configured public key before installation; a
+/// failed verification returns an `Err` message string rather than silently
+/// skipping the install. The error is not typed: a verification failure and a
+/// download failure produce the same kind of message.
+#[cfg(desktop)]
 #[tauri::command]
-fn get_platform_info() -> serde_json::Value {
-    serde_json::json!({
-        "os": std::env::consts::OS,
-        "arch": std::env::consts::ARCH,
-        "family": std::env::consts::FAMILY,
-    })
+async fn download_and_install_update(app: tauri::AppHandle) -> Result<(), String> {
+    let update = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {e}"))?
+        .ok_or_else(|| "No update is available to install".to_string())?;
+
+    let mut downloaded = 0usize;
+    update
+        .download_and_install(
+            |chunk_length, content_length| {
+                downloaded += chunk_length;
+                let _ = app.emit(
+                    "update://download-progress",
+                    UpdateProgress {
+                        downloaded,
+                        content_length,
+                    },
+                );
+            },
+            || {
+                let _ = app.emit("update://finished", ());
+            },
+        )
+        .await
+        .map_err(|e| format!("Failed to download/verify update: {e}"))?;
+
+    restart_app(app);
+
+    Ok(())
 }
 
-/// Custom Tauri command to open external URL in default browser
+/// Restarts the application, e.g. after installing an update. Delegates to
+/// the already-registered process plugin rather than reimplementing restart.
+/// Exposed as its own command too, so the frontend can offer a manual
+/// "restart now" action outside of the update flow.
+/// Desktop-only: `tauri-plugin-process` has no mobile implementation.
+#[cfg(desktop)]
 #[tauri::command]
-async fn open_external_url(url: String) -> Result<(), String> {
-    open::that(&url).map_err(|e| e.to_string())
+fn restart_app(app: tauri::AppHandle) {
+    tauri_plugin_process::restart(&app.env());
 }
 
 /// Initialize logging
@@ -40,37 +313,99 @@ pub fn run() {
 
     log::info!("Starting Flowent Desktop v{}", env!("CARGO_PKG_VERSION"));
 
-    tauri::Builder::default()
-        // Register plugins
+    let builder = tauri::Builder::default();
+
+    // Guard against duplicate launches; must run before the other plugins so it can
+    // short-circuit startup as early as possible. No mobile equivalent: the OS never
+    // hands a mobile app a second launch the way it does on desktop.
+    #[cfg(desktop)]
+    let builder = builder.plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+        log::info!("Second instance detected, focusing existing window");
+
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.unminimize();
+            let _ = window.set_focus();
+        }
+
+        let _ = app.emit("second-instance", SecondInstancePayload { args, cwd });
+    }));
+
+    // Register plugins
+    let builder = builder
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_http::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_notification::init())
-        .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(flowent_plugin::init());
+
+    // Process control, global shortcuts and auto-updates have no mobile backend,
+    // so they're only wired into the plugin chain on desktop targets.
+    #[cfg(desktop)]
+    let builder = builder
+        .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_store::Builder::new().build())
+        .manage(ShortcutRegistry::default());
+
+    builder
         // Register custom commands
-        .invoke_handler(tauri::generate_handler![
-            get_app_version,
-            get_platform_info,
-            open_external_url,
-        ])
+        .invoke_handler({
+            #[cfg(desktop)]
+            {
+                tauri::generate_handler![
+                    register_shortcut,
+                    unregister_shortcut,
+                    get_shortcuts,
+                    check_for_update,
+                    download_and_install_update,
+                    restart_app,
+                ]
+            }
+            #[cfg(not(desktop))]
+            {
+                tauri::generate_handler![]
+            }
+        })
         // Setup hook
         .setup(|app| {
             log::info!("App setup complete");
 
-            // Get the main window
+            // Set window title and open devtools; neither operation is valid on
+            // mobile webviews, which have no window chrome to speak of.
+            #[cfg(desktop)]
             if let Some(window) = app.get_webview_window("main") {
-                // Set window title with version
                 let version = env!("CARGO_PKG_VERSION");
                 let _ = window.set_title(&format!("Flowent AI v{}", version));
 
-                // Show devtools in development
                 #[cfg(debug_assertions)]
-                {
-                    window.open_devtools();
+                window.open_devtools();
+            }
+
+            // Re-apply previously persisted shortcut bindings (the OS-level
+            // registration itself doesn't survive a restart, only the user's
+            // chosen accelerators do), falling back to the built-in default
+            // for quick-capture if nothing was persisted yet.
+            #[cfg(desktop)]
+            {
+                let mut persisted = load_persisted_shortcuts(app.handle());
+                persisted
+                    .entry("quick-capture:toggle".to_string())
+                    .or_insert_with(|| DEFAULT_QUICK_CAPTURE_SHORTCUT.to_string());
+                persisted
+                    .entry("quick-capture:dismiss".to_string())
+                    .or_insert_with(|| DEFAULT_QUICK_CAPTURE_DISMISS_SHORTCUT.to_string());
+
+                for (action, accelerator) in persisted {
+                    if let Err(err) =
+                        register_shortcut(app.handle().clone(), accelerator, action.clone())
+                    {
+                        log::warn!("Failed to register shortcut for '{action}': {err}");
+                    }
                 }
             }
 